@@ -0,0 +1,68 @@
+//! Audio alarm playback, enabled by the `sound` Cargo feature.
+
+use std::io::{BufReader, Cursor};
+use std::path::Path;
+
+use rodio::{Decoder, OutputStream, Sink};
+
+/// A short built-in beep, played when the user hasn't configured a sound
+/// file of their own.
+const EMBEDDED_ALARM: &[u8] = include_bytes!("../assets/alarm.wav");
+
+/// Whether an alarm plays once or repeats until dismissed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AlarmMode {
+    #[default]
+    OneShot,
+    Looping,
+}
+
+/// Decodes and plays an alarm sound on a detached audio stream.
+pub struct AlarmPlayer;
+
+impl AlarmPlayer {
+    /// Play `path` in the given `mode`, falling back to the embedded default
+    /// beep when `path` is `None` (or empty).
+    ///
+    /// Playback runs on its own `OutputStream`/`Sink` pair that is detached
+    /// from this call, so the sound keeps playing after `play` returns
+    /// without the caller needing to hold anything alive.
+    pub fn play(path: Option<&Path>, mode: AlarmMode) {
+        let (stream, handle) = match OutputStream::try_default() {
+            Ok(pair) => pair,
+            Err(_) => return,
+        };
+        let sink = match Sink::try_new(&handle) {
+            Ok(sink) => sink,
+            Err(_) => return,
+        };
+
+        match path {
+            Some(path) => {
+                let file = match std::fs::File::open(path) {
+                    Ok(file) => file,
+                    Err(_) => return,
+                };
+                match Decoder::new(BufReader::new(file)) {
+                    Ok(source) => match mode {
+                        AlarmMode::OneShot => sink.append(source),
+                        AlarmMode::Looping => sink.append(source.repeat_infinite()),
+                    },
+                    Err(_) => return,
+                }
+            }
+            None => match Decoder::new(Cursor::new(EMBEDDED_ALARM)) {
+                Ok(source) => match mode {
+                    AlarmMode::OneShot => sink.append(source),
+                    AlarmMode::Looping => sink.append(source.repeat_infinite()),
+                },
+                Err(_) => return,
+            },
+        }
+
+        sink.detach();
+        // Leak the stream so the output device stays open for the detached
+        // sink; rodio has no way to hand that ownership to the sink itself.
+        std::mem::forget(stream);
+    }
+}
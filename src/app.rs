@@ -1,6 +1,8 @@
+use chrono;
 use eframe;
 use egui;
 
+use crate::chime::ChimeSchedule;
 use crate::clock::Clock;
 use crate::stopwatch::Stopwatch;
 use crate::timer::Timer;
@@ -11,6 +13,7 @@ pub struct App {
     stopwatch: Stopwatch,
     timer: Timer,
     current_tab: Tab,
+    chimes: ChimeSchedule,
 }
 
 #[derive(Default, PartialEq)]
@@ -23,6 +26,13 @@ enum Tab {
 
 impl eframe::App for App {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        if self.chimes.tick(chrono::Local::now()) {
+            #[cfg(feature = "sound")]
+            crate::alarm::AlarmPlayer::play(None, crate::alarm::AlarmMode::OneShot);
+            #[cfg(feature = "desktop")]
+            crate::notify::notify("CCC Clock", "Chime!");
+        }
+
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.horizontal(|ui| {
                 ui.selectable_value(&mut self.current_tab, Tab::Clock, "Clock");
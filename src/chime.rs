@@ -0,0 +1,159 @@
+//! Recurring wall-clock chimes, configured by a schedule file.
+//!
+//! Each rule matches a `(hour, minute, second)` pattern where any field left
+//! out of the config is treated as a wildcard, e.g. `{ minute = 0, second =
+//! 0 }` fires on the hour, and `{ second = 0 }` fires every minute.
+
+use std::fs;
+use std::path::Path;
+
+use chrono::{DateTime, Datelike, Local, Timelike};
+use serde::Deserialize;
+
+/// Default location of the chime schedule, relative to the working directory.
+pub const DEFAULT_CONFIG_PATH: &str = "chimes.toml";
+
+/// A single `(hour, minute, second)` match pattern; `None` means "any".
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+pub struct ChimeRule {
+    pub hour: Option<u32>,
+    pub minute: Option<u32>,
+    pub second: Option<u32>,
+}
+
+impl ChimeRule {
+    fn matches(&self, hour: u32, minute: u32, second: u32) -> bool {
+        self.hour.is_none_or(|h| h == hour)
+            && self.minute.is_none_or(|m| m == minute)
+            && self.second.is_none_or(|s| s == second)
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ChimeConfig {
+    #[serde(rename = "rule", default)]
+    rules: Vec<ChimeRule>,
+}
+
+/// Load chime rules from `path`, returning an empty schedule if the file is
+/// missing or can't be parsed.
+fn load_rules(path: &Path) -> Vec<ChimeRule> {
+    let Ok(contents) = fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    toml::from_str::<ChimeConfig>(&contents)
+        .map(|config| config.rules)
+        .unwrap_or_default()
+}
+
+/// Tracks a set of [`ChimeRule`]s and fires each wall-clock second at most
+/// once per day.
+pub struct ChimeSchedule {
+    rules: Vec<ChimeRule>,
+    // (day number, second-of-day) of the last match, so a rule that only
+    // matches once per day (e.g. a daily 9:00:00 reminder) fires again the
+    // next day instead of being blocked forever.
+    last_fired: Option<(i32, u32)>,
+}
+
+impl ChimeSchedule {
+    /// Load the schedule from `path`.
+    pub fn load(path: &Path) -> Self {
+        Self {
+            rules: load_rules(path),
+            last_fired: None,
+        }
+    }
+
+    /// Check `now` against the rules, returning `true` at most once per
+    /// matching second per day.
+    pub fn tick(&mut self, now: DateTime<Local>) -> bool {
+        let second_of_day = now.hour() * 3600 + now.minute() * 60 + now.second();
+        let key = (now.num_days_from_ce(), second_of_day);
+        if self.last_fired == Some(key) {
+            return false;
+        }
+
+        let matched = self
+            .rules
+            .iter()
+            .any(|rule| rule.matches(now.hour(), now.minute(), now.second()));
+
+        if matched {
+            self.last_fired = Some(key);
+        }
+        matched
+    }
+}
+
+impl Default for ChimeSchedule {
+    fn default() -> Self {
+        Self::load(Path::new(DEFAULT_CONFIG_PATH))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(hour: u32, minute: u32, second: u32) -> DateTime<Local> {
+        Local.with_ymd_and_hms(2024, 1, 1, hour, minute, second).unwrap()
+    }
+
+    fn on_day(day: u32, hour: u32, minute: u32, second: u32) -> DateTime<Local> {
+        Local.with_ymd_and_hms(2024, 1, day, hour, minute, second).unwrap()
+    }
+
+    #[test]
+    fn test_rule_with_all_wildcards_matches_every_second() {
+        let rule = ChimeRule::default();
+        assert!(rule.matches(0, 0, 0));
+        assert!(rule.matches(23, 59, 59));
+    }
+
+    #[test]
+    fn test_rule_with_second_only_matches_every_minute() {
+        let rule = ChimeRule {
+            hour: None,
+            minute: None,
+            second: Some(0),
+        };
+        assert!(rule.matches(3, 14, 0));
+        assert!(rule.matches(9, 0, 0));
+        assert!(!rule.matches(3, 14, 1));
+    }
+
+    #[test]
+    fn test_tick_fires_once_then_stays_quiet_within_the_same_second() {
+        let mut schedule = ChimeSchedule {
+            rules: vec![ChimeRule {
+                hour: None,
+                minute: None,
+                second: Some(0),
+            }],
+            last_fired: None,
+        };
+
+        assert!(schedule.tick(at(10, 0, 0)));
+        assert!(!schedule.tick(at(10, 0, 0)));
+        assert!(!schedule.tick(at(10, 0, 1)));
+        assert!(schedule.tick(at(10, 1, 0)));
+    }
+
+    #[test]
+    fn test_tick_refires_a_once_daily_rule_on_the_next_day() {
+        let mut schedule = ChimeSchedule {
+            rules: vec![ChimeRule {
+                hour: Some(9),
+                minute: Some(0),
+                second: Some(0),
+            }],
+            last_fired: None,
+        };
+
+        assert!(schedule.tick(on_day(1, 9, 0, 0)));
+        assert!(!schedule.tick(on_day(1, 9, 0, 0)));
+        assert!(schedule.tick(on_day(2, 9, 0, 0)));
+    }
+}
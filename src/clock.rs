@@ -1,8 +1,12 @@
 use chrono;
 use egui;
 
+use crate::timebar::{self, TimeBarLength};
+
 #[derive(Default)]
-pub struct Clock {}
+pub struct Clock {
+    timebar_length: TimeBarLength,
+}
 
 impl Clock {
     pub fn ui(&mut self, ui: &mut egui::Ui) {
@@ -27,6 +31,9 @@ impl Clock {
                     );
                 });
             });
+
+            ui.add_space(20.0);
+            timebar::ui(ui, &mut self.timebar_length);
         });
     }
 }
\ No newline at end of file
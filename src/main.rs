@@ -1,6 +1,12 @@
+#[cfg(feature = "sound")]
+pub mod alarm;
 pub mod app;
+pub mod chime;
 pub mod clock;
+#[cfg(feature = "desktop")]
+pub mod notify;
 pub mod stopwatch;
+pub mod timebar;
 pub mod timer;
 pub mod utils;
 
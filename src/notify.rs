@@ -0,0 +1,8 @@
+//! Desktop notifications, enabled by the `desktop` Cargo feature.
+
+use notify_rust::Notification;
+
+/// Fire a native OS notification with the given title and body.
+pub fn notify(summary: &str, body: &str) {
+    let _ = Notification::new().summary(summary).body(body).show();
+}
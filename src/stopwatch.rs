@@ -1,22 +1,40 @@
+use std::time::Instant;
+
 use egui;
 
+use crate::utils::format_time_precise;
+
 #[derive(Default)]
 pub struct Stopwatch {
-    elapsed_time: f64,
+    start_time: Option<Instant>,
+    offset: f64,
     is_running: bool,
+    laps: Vec<f64>,
 }
 
 impl Stopwatch {
+    /// Total elapsed time in seconds, including the run in progress if any.
+    ///
+    /// Anchored on `Instant` rather than wall-clock time so a backward clock
+    /// adjustment (NTP sync, DST, manual change) can't make this go negative.
+    fn elapsed_time(&self) -> f64 {
+        match self.start_time {
+            Some(start) => self.offset + start.elapsed().as_secs_f64(),
+            None => self.offset,
+        }
+    }
+
     pub fn ui(&mut self, ui: &mut egui::Ui) {
         ui.vertical_centered(|ui| {
             ui.heading("Stopwatch");
             ui.separator();
-            
+
             // Display time
-            let minutes = (self.elapsed_time / 60.0) as u32;
-            let seconds = (self.elapsed_time % 60.0) as u32;
-            let centiseconds = ((self.elapsed_time % 1.0) * 100.0) as u32;
-            
+            let elapsed_time = self.elapsed_time();
+            let minutes = (elapsed_time / 60.0) as u32;
+            let seconds = (elapsed_time % 60.0) as u32;
+            let centiseconds = ((elapsed_time % 1.0) * 100.0) as u32;
+
             ui.allocate_ui(egui::vec2(300.0, 80.0), |ui| {
                 ui.centered_and_justified(|ui| {
                     ui.label(
@@ -26,31 +44,57 @@ impl Stopwatch {
                     );
                 });
             });
-            
+
             ui.add_space(20.0);
-            
+
             // Controls
             ui.horizontal(|ui| {
                 if self.is_running {
                     if ui.button("Stop").clicked() {
+                        self.offset = self.elapsed_time();
+                        self.start_time = None;
                         self.is_running = false;
                     }
+                    if ui.button("Lap").clicked() {
+                        self.laps.push(elapsed_time);
+                    }
                 } else {
                     if ui.button("Start").clicked() {
+                        self.start_time = Some(Instant::now());
                         self.is_running = true;
                     }
                 }
-                
+
                 if ui.button("Reset").clicked() {
-                    self.elapsed_time = 0.0;
+                    self.offset = 0.0;
+                    self.start_time = None;
                     self.is_running = false;
+                    self.laps.clear();
                 }
             });
-            
-            // Update elapsed time
+
+            if !self.laps.is_empty() {
+                ui.add_space(10.0);
+                egui::ScrollArea::vertical().max_height(120.0).show(ui, |ui| {
+                    for (i, &cumulative) in self.laps.iter().enumerate() {
+                        let split = if i == 0 {
+                            cumulative
+                        } else {
+                            cumulative - self.laps[i - 1]
+                        };
+                        ui.label(format!(
+                            "Lap {}: split {}, total {}",
+                            i + 1,
+                            format_time_precise(split),
+                            format_time_precise(cumulative)
+                        ));
+                    }
+                });
+            }
+
             if self.is_running {
-                self.elapsed_time += ui.input(|i| i.unstable_dt) as f64;
+                ui.ctx().request_repaint();
             }
         });
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,122 @@
+//! A progress bar showing how far through the current minute/hour/day we are.
+
+use chrono::Timelike;
+use egui;
+
+/// The period a [`TimeBarLength`] measures elapsed time against.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum TimeBarLength {
+    #[default]
+    Minute,
+    Hour,
+    Day,
+    Custom(f64),
+}
+
+impl TimeBarLength {
+    /// Length of this period in seconds.
+    pub fn as_secs(&self) -> f64 {
+        match self {
+            TimeBarLength::Minute => 60.0,
+            TimeBarLength::Hour => 3600.0,
+            TimeBarLength::Day => 86400.0,
+            TimeBarLength::Custom(secs) => *secs,
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            TimeBarLength::Minute => "Minute",
+            TimeBarLength::Hour => "Hour",
+            TimeBarLength::Day => "Day",
+            TimeBarLength::Custom(_) => "Custom",
+        }
+    }
+}
+
+/// Seconds elapsed within the current `length` period, for `now`.
+fn elapsed_in_period(now: chrono::DateTime<chrono::Local>, length: TimeBarLength) -> f64 {
+    let frac_secs = now.nanosecond() as f64 / 1_000_000_000.0;
+    match length {
+        TimeBarLength::Minute => now.second() as f64 + frac_secs,
+        TimeBarLength::Hour => (now.minute() * 60 + now.second()) as f64 + frac_secs,
+        TimeBarLength::Day => {
+            (now.hour() * 3600 + now.minute() * 60 + now.second()) as f64 + frac_secs
+        }
+        TimeBarLength::Custom(secs) => {
+            let since_midnight =
+                now.hour() as f64 * 3600.0 + now.minute() as f64 * 60.0 + now.second() as f64;
+            (since_midnight + frac_secs) % secs
+        }
+    }
+}
+
+/// Default period used the first time the user switches to "Custom".
+const DEFAULT_CUSTOM_SECS: f64 = 300.0;
+
+/// Draw a period picker and a progress bar for how far through it we are.
+pub fn ui(ui: &mut egui::Ui, length: &mut TimeBarLength) {
+    ui.horizontal(|ui| {
+        ui.label("Period:");
+        egui::ComboBox::from_id_source("timebar_period")
+            .selected_text(length.label())
+            .show_ui(ui, |ui| {
+                ui.selectable_value(length, TimeBarLength::Minute, "Minute");
+                ui.selectable_value(length, TimeBarLength::Hour, "Hour");
+                ui.selectable_value(length, TimeBarLength::Day, "Day");
+                let is_custom = matches!(length, TimeBarLength::Custom(_));
+                if ui.selectable_label(is_custom, "Custom").clicked() && !is_custom {
+                    *length = TimeBarLength::Custom(DEFAULT_CUSTOM_SECS);
+                }
+            });
+    });
+
+    if let TimeBarLength::Custom(secs) = length {
+        ui.horizontal(|ui| {
+            ui.label("Custom period (seconds):");
+            ui.add(egui::DragValue::new(secs).clamp_range(1.0..=86400.0));
+        });
+    }
+
+    let now = chrono::Local::now();
+    let ratio = (elapsed_in_period(now, *length) / length.as_secs()).clamp(0.0, 1.0);
+    ui.add(egui::ProgressBar::new(ratio as f32).show_percentage());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(hour: u32, minute: u32, second: u32) -> chrono::DateTime<chrono::Local> {
+        chrono::Local
+            .with_ymd_and_hms(2024, 1, 1, hour, minute, second)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_elapsed_in_period_minute() {
+        assert_eq!(elapsed_in_period(at(10, 20, 30), TimeBarLength::Minute), 30.0);
+    }
+
+    #[test]
+    fn test_elapsed_in_period_hour() {
+        assert_eq!(elapsed_in_period(at(10, 20, 30), TimeBarLength::Hour), 20.0 * 60.0 + 30.0);
+    }
+
+    #[test]
+    fn test_elapsed_in_period_day() {
+        assert_eq!(
+            elapsed_in_period(at(10, 20, 30), TimeBarLength::Day),
+            10.0 * 3600.0 + 20.0 * 60.0 + 30.0
+        );
+    }
+
+    #[test]
+    fn test_elapsed_in_period_custom() {
+        assert_eq!(
+            elapsed_in_period(at(0, 2, 5), TimeBarLength::Custom(90.0)),
+            35.0
+        );
+    }
+}
@@ -1,20 +1,55 @@
+use std::time::Instant;
+
 use egui;
 
+#[cfg(feature = "sound")]
+use crate::alarm::{AlarmMode, AlarmPlayer};
+
 #[derive(Default)]
 pub struct Timer {
     target_minutes: u32,
     target_seconds: u32,
-    remaining_time: f64,
+    duration_input: String,
+    target_time: f64,
+    start_time: Option<Instant>,
+    offset: f64,
     is_running: bool,
     is_finished: bool,
+    #[cfg(feature = "sound")]
+    sound_enabled: bool,
+    #[cfg(feature = "sound")]
+    sound_mode: AlarmMode,
+    /// Custom sound file to play instead of the embedded default beep.
+    #[cfg(feature = "sound")]
+    sound_path: String,
+    #[cfg(feature = "sound")]
+    did_alarm: bool,
+    #[cfg(feature = "desktop")]
+    did_notify: bool,
 }
 
 impl Timer {
+    /// Total time elapsed since Start, including the run in progress if any.
+    ///
+    /// Anchored on `Instant` rather than wall-clock time so a backward clock
+    /// adjustment (NTP sync, DST, manual change) can't make this go negative.
+    fn elapsed(&self) -> f64 {
+        match self.start_time {
+            Some(start) => self.offset + start.elapsed().as_secs_f64(),
+            None => self.offset,
+        }
+    }
+
+    /// Time left on the countdown, floored at zero.
+    fn remaining_time(&self) -> f64 {
+        (self.target_time - self.elapsed()).max(0.0)
+    }
+
     pub fn ui(&mut self, ui: &mut egui::Ui) {
         ui.vertical_centered(|ui| {
             ui.heading("Timer");
             ui.separator();
-            
+
             // Timer setup
             if !self.is_running {
                 ui.horizontal(|ui| {
@@ -23,18 +58,59 @@ impl Timer {
                     ui.label("Seconds:");
                     ui.add(egui::DragValue::new(&mut self.target_seconds).clamp_range(0..=59));
                 });
-                
+
+                ui.horizontal(|ui| {
+                    ui.label("Or enter e.g. \"1h30m\", \"90s\", \"5:30\":");
+                    ui.text_edit_singleline(&mut self.duration_input);
+                    if ui.button("Set").clicked() {
+                        if let Some(secs) = crate::utils::parse_duration(&self.duration_input) {
+                            // Clamp to what the Minutes/Seconds DragValues above
+                            // can represent (0..=59 each) so the setup UI never
+                            // shows an out-of-range value.
+                            let secs = (secs.max(0.0) as u32).min(59 * 60 + 59);
+                            self.target_minutes = secs / 60;
+                            self.target_seconds = secs % 60;
+                        }
+                    }
+                });
+
+                #[cfg(feature = "sound")]
+                {
+                    ui.checkbox(&mut self.sound_enabled, "Play sound when finished");
+                    if self.sound_enabled {
+                        ui.horizontal(|ui| {
+                            ui.radio_value(&mut self.sound_mode, AlarmMode::OneShot, "Once");
+                            ui.radio_value(&mut self.sound_mode, AlarmMode::Looping, "Loop");
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label("Sound file (blank = built-in beep):");
+                            ui.text_edit_singleline(&mut self.sound_path);
+                        });
+                    }
+                }
+
                 if ui.button("Start Timer").clicked() {
-                    self.remaining_time = (self.target_minutes * 60 + self.target_seconds) as f64;
+                    self.target_time = (self.target_minutes * 60 + self.target_seconds) as f64;
+                    self.start_time = Some(Instant::now());
+                    self.offset = 0.0;
                     self.is_running = true;
                     self.is_finished = false;
+                    #[cfg(feature = "sound")]
+                    {
+                        self.did_alarm = false;
+                    }
+                    #[cfg(feature = "desktop")]
+                    {
+                        self.did_notify = false;
+                    }
                 }
             }
-            
+
             // Timer display
-            let minutes = (self.remaining_time / 60.0) as u32;
-            let seconds = (self.remaining_time % 60.0) as u32;
-            
+            let remaining_time = self.remaining_time();
+            let minutes = (remaining_time / 60.0) as u32;
+            let seconds = (remaining_time % 60.0) as u32;
+
             ui.allocate_ui(egui::vec2(300.0, 80.0), |ui| {
                 ui.centered_and_justified(|ui| {
                     let color = if self.is_finished {
@@ -42,7 +118,7 @@ impl Timer {
                     } else {
                         egui::Color32::from_rgb(255, 255, 255)
                     };
-                    
+
                     ui.label(
                         egui::RichText::new(format!("{:02}:{:02}", minutes, seconds))
                             .size(48.0)
@@ -51,26 +127,47 @@ impl Timer {
                     );
                 });
             });
-            
+
             // Timer controls
             if self.is_running {
                 if ui.button("Stop Timer").clicked() {
+                    self.offset = self.elapsed();
+                    self.start_time = None;
                     self.is_running = false;
                     self.is_finished = false;
+                    #[cfg(feature = "sound")]
+                    {
+                        self.did_alarm = false;
+                    }
+                    #[cfg(feature = "desktop")]
+                    {
+                        self.did_notify = false;
+                    }
                 }
-                
+
                 // Update timer
-                if self.remaining_time > 0.0 {
-                    self.remaining_time -= ui.input(|i| i.unstable_dt) as f64;
-                } else {
-                    self.remaining_time = 0.0;
-                    self.is_finished = true;
+                self.is_finished = remaining_time <= 0.0;
+
+                #[cfg(feature = "sound")]
+                if self.is_finished && self.sound_enabled && !self.did_alarm {
+                    self.did_alarm = true;
+                    let custom_path = (!self.sound_path.trim().is_empty())
+                        .then(|| std::path::Path::new(self.sound_path.trim()));
+                    AlarmPlayer::play(custom_path, self.sound_mode);
+                }
+
+                #[cfg(feature = "desktop")]
+                if self.is_finished && !self.did_notify {
+                    self.did_notify = true;
+                    crate::notify::notify("CCC Clock", "Time's up!");
                 }
+
+                ui.ctx().request_repaint();
             }
-            
+
             if self.is_finished {
                 ui.label(egui::RichText::new("⏰ Time's up!").size(24.0).strong());
             }
         });
     }
-}
\ No newline at end of file
+}
@@ -20,6 +20,54 @@ pub fn to_seconds(minutes: u32, seconds: u32) -> f64 {
     (minutes * 60 + seconds) as f64
 }
 
+/// Parse a free-text duration like `1h30m`, `90s`, or `5:30` into seconds.
+///
+/// Accepts a colon-separated form (`MM:SS` or `HH:MM:SS`, most-significant
+/// unit first) or a sequence of `<number><unit>` pairs using `h`/`m`/`s`.
+/// Returns `None` if `input` doesn't match either form.
+pub fn parse_duration(input: &str) -> Option<f64> {
+    let input = input.trim();
+    if input.is_empty() {
+        return None;
+    }
+
+    if input.contains(':') {
+        let mut seconds = 0.0;
+        for part in input.split(':') {
+            let value: f64 = part.parse().ok()?;
+            seconds = seconds * 60.0 + value;
+        }
+        return Some(seconds);
+    }
+
+    let mut total = 0.0;
+    let mut number = String::new();
+    let mut matched_any = false;
+    for ch in input.chars() {
+        if ch.is_ascii_digit() || ch == '.' {
+            number.push(ch);
+            continue;
+        }
+        let value: f64 = number.parse().ok()?;
+        number.clear();
+        let unit_seconds = match ch {
+            'h' => 3600.0,
+            'm' => 60.0,
+            's' => 1.0,
+            _ => return None,
+        };
+        total += value * unit_seconds;
+        matched_any = true;
+    }
+
+    if !number.is_empty() {
+        total += number.parse::<f64>().ok()?;
+        matched_any = true;
+    }
+
+    matched_any.then_some(total)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -43,4 +91,29 @@ mod tests {
         assert_eq!(to_seconds(0, 59), 59.0);
         assert_eq!(to_seconds(1, 1), 61.0);
     }
+
+    #[test]
+    fn test_parse_duration_units() {
+        assert_eq!(parse_duration("90s"), Some(90.0));
+        assert_eq!(parse_duration("1h30m"), Some(5400.0));
+        assert_eq!(parse_duration("5:30"), Some(330.0));
+        assert_eq!(parse_duration("1:02:03"), Some(3723.0));
+    }
+
+    #[test]
+    fn test_parse_duration_invalid() {
+        assert_eq!(parse_duration(""), None);
+        assert_eq!(parse_duration("abc"), None);
+    }
+
+    #[test]
+    fn test_parse_duration_round_trip() {
+        for seconds in [5.0, 65.0, 3661.0] {
+            let formatted = format_time(seconds);
+            let mut parts = formatted.split(':');
+            let minutes: f64 = parts.next().unwrap().parse().unwrap();
+            let secs: f64 = parts.next().unwrap().parse().unwrap();
+            assert_eq!(parse_duration(&formatted), Some(minutes * 60.0 + secs));
+        }
+    }
 }
\ No newline at end of file